@@ -1,84 +1,914 @@
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use serde::Serialize;
-use pyo3::prelude::*;
-use serde_json;
-
-#[pyfunction]
-fn parse_adeck() -> PyResult<()> {
-    // Your function implementation here
-    Ok(())
-}
-
-#[pymodule]
-fn adeck_parser(py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(parse_adeck, m)?)?;
-    Ok(())
-}
-
-#[derive(Serialize, Debug)]
-struct AdeckEntry {
-    basin: String,
-    storm_number: u8,
-    init_time: String,
-    run_id: u8,
-    model: String,
-    forecast_hour: i16,
-    latitude: String,
-    longitude: String,
-    wind_speed: u16,
-    pressure: Option<u16>,
-    storm_type: String,
-    radii_34: Vec<u16>,
-}
-
-fn parse_adeck_line(line: &str) -> Option<AdeckEntry> {
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float32Builder, Int32Builder, StringBuilder};
+use arrow::compute::concat_batches;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use memmap2::Mmap;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+/// Errors raised while reading or parsing an ATCF deck.
+#[derive(Debug)]
+pub enum AdeckError {
+    /// The file could not be opened or memory-mapped.
+    Io(String, std::io::Error),
+    /// An Arrow operation (batch construction, concat) failed.
+    Arrow(ArrowError),
+    /// A row carried fewer columns than the mandatory position set.
+    TooFewColumns { line: usize, found: usize },
+    /// A column held a value that could not be decoded.
+    Parse {
+        line: usize,
+        column: &'static str,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for AdeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdeckError::Io(path, e) => write!(f, "{path}: {e}"),
+            AdeckError::Arrow(e) => write!(f, "arrow error: {e}"),
+            AdeckError::TooFewColumns { line, found } => {
+                write!(f, "line {line}: too few columns, found {found}")
+            }
+            AdeckError::Parse {
+                line,
+                column,
+                value,
+            } => write!(f, "line {line}: column '{column}': could not parse {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for AdeckError {}
+
+impl AdeckError {
+    /// The source line this error was attributed to, for variants that carry
+    /// one. `Io`/`Arrow` failures aren't tied to a single row.
+    fn line(&self) -> Option<usize> {
+        match self {
+            AdeckError::TooFewColumns { line, .. } | AdeckError::Parse { line, .. } => Some(*line),
+            AdeckError::Io(..) | AdeckError::Arrow(..) => None,
+        }
+    }
+}
+
+impl From<ArrowError> for AdeckError {
+    fn from(e: ArrowError) -> Self {
+        AdeckError::Arrow(e)
+    }
+}
+
+/// True for ATCF tokens that represent a missing value (blank or `****`).
+fn is_blank(token: &str) -> bool {
+    token.is_empty() || token.bytes().all(|b| b == b'*')
+}
+
+/// One parsed ATCF A-deck forecast line.
+///
+/// The field set mirrors the full ATCF column layout: the leading
+/// forecast/position columns followed by the optional wind-radii, pressure
+/// and storm-metadata columns that forecast centers leave blank more often
+/// than not. Columns that ATCF may omit are modelled as `Option` so a blank
+/// token lands as a null in Arrow rather than a fabricated `0`.
+///
+/// `rad`/`rad_neq`/`rad_seq`/`rad_swq`/`rad_nwq` model one wind-radii row per
+/// threshold rather than fixed 34/50/64-kt quadrant columns: a single ATCF
+/// line only ever reports radii for the threshold named in its own `rad`
+/// column, and a forecast center that reports all three thresholds for one
+/// forecast hour emits three separate lines (three separate rows here), one
+/// per threshold. Columns named per-threshold (`rad34_neq`, `rad50_neq`, ...)
+/// would sit null on every row but one, so the one-`rad`-per-row shape is the
+/// layout ATCF itself already uses; `rad` tells a consumer which threshold a
+/// given row's quadrants belong to.
+///
+/// The field set intentionally stops at `seas4`. The trailing `userdefinedN`
+/// / `userdataN` pairs the ATCF spec allows past that point are free-form,
+/// per-forecast-center key/value text with no fixed count or shared meaning
+/// across models, so there's no common schema to model them against; any
+/// columns past `seas4` are ignored when a line is parsed.
+#[derive(Debug)]
+pub struct AdeckEntry {
+    pub basin: String,
+    pub storm_number: i32,
+    pub init_time: String,
+    pub run_id: Option<i32>,
+    pub model: String,
+    pub forecast_hour: i32,
+    pub latitude: Option<f32>,
+    pub longitude: Option<f32>,
+    pub wind_speed: Option<i32>,
+    pub pressure: Option<i32>,
+    pub storm_type: String,
+    pub rad: Option<i32>,
+    pub windcode: String,
+    pub rad_neq: Option<i32>,
+    pub rad_seq: Option<i32>,
+    pub rad_swq: Option<i32>,
+    pub rad_nwq: Option<i32>,
+    pub pouter: Option<i32>,
+    pub router: Option<i32>,
+    pub rmw: Option<i32>,
+    pub gusts: Option<i32>,
+    pub eye: Option<i32>,
+    pub subregion: String,
+    pub maxseas: Option<i32>,
+    pub initials: String,
+    pub direction: Option<i32>,
+    pub speed: Option<i32>,
+    pub stormname: String,
+    pub depth: String,
+    pub seas: Option<i32>,
+    pub seascode: String,
+    pub seas1: Option<i32>,
+    pub seas2: Option<i32>,
+    pub seas3: Option<i32>,
+    pub seas4: Option<i32>,
+}
+
+/// Parse a mandatory ATCF integer column, erroring if the token is absent or
+/// not a valid integer.
+fn req_int(
+    token: &str,
+    column: &'static str,
+    line: usize,
+) -> Result<i32, AdeckError> {
+    token.parse().map_err(|_| AdeckError::Parse {
+        line,
+        column,
+        value: token.to_string(),
+    })
+}
+
+/// Parse an optional ATCF integer column. Blanks and `****` placeholders decode
+/// to `None`; any other unparseable token is an error.
+fn opt_int(
+    token: &str,
+    column: &'static str,
+    line: usize,
+) -> Result<Option<i32>, AdeckError> {
+    if is_blank(token) {
+        return Ok(None);
+    }
+    token.parse().map(Some).map_err(|_| AdeckError::Parse {
+        line,
+        column,
+        value: token.to_string(),
+    })
+}
+
+/// Parse an optional ATCF coordinate column, erroring on a non-blank token that
+/// fails to decode.
+fn opt_coordinate(
+    token: &str,
+    pos: char,
+    neg: char,
+    column: &'static str,
+    line: usize,
+) -> Result<Option<f32>, AdeckError> {
+    let value = parse_coordinate(token, pos, neg);
+    if value.is_none() && !is_blank(token) {
+        return Err(AdeckError::Parse {
+            line,
+            column,
+            value: token.to_string(),
+        });
+    }
+    Ok(value)
+}
+
+/// Decode an ATCF latitude/longitude token.
+///
+/// ATCF stores coordinates as integer tenths of a degree followed by a
+/// hemisphere letter (e.g. `261N` = 26.1°N, `0805W` = -80.5°). The magnitude
+/// is divided by ten and negated in the southern/western hemisphere. Empty or
+/// `****` placeholder tokens parse as `None`.
+pub fn parse_coordinate(coord: &str, pos: char, neg: char) -> Option<f32> {
+    parse_coordinate_scaled(coord, pos, neg, 10.0)
+}
+
+/// Decode an ATCF coordinate token with an explicit tenths/hundredths divisor.
+///
+/// A-deck and B-deck store coordinates in tenths of a degree; F-deck fix
+/// records use hundredths.
+pub fn parse_coordinate_scaled(coord: &str, pos: char, neg: char, scale: f32) -> Option<f32> {
+    if coord.is_empty() || coord.bytes().all(|b| b == b'*') {
+        return None;
+    }
+    let last_char = coord.chars().last()?;
+    let value: f32 = coord[..coord.len() - 1].parse().ok()?;
+    match last_char {
+        c if c == pos => Some(value / scale),
+        c if c == neg => Some(-value / scale),
+        _ => None,
+    }
+}
+
+fn field<'a>(fields: &[&'a str], idx: usize) -> &'a str {
+    fields.get(idx).copied().unwrap_or("")
+}
+
+/// Parse a single ATCF A-deck line into an [`AdeckEntry`].
+///
+/// `line_no` is the 1-based source line, used to locate parse failures.
+/// Returns [`AdeckError::TooFewColumns`] for lines too short to carry the
+/// mandatory position columns and [`AdeckError::Parse`] for a column that
+/// fails to decode.
+pub fn parse_adeck_line(line: &str, line_no: usize) -> Result<AdeckEntry, AdeckError> {
+    let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+    if fields.len() < 11 {
+        return Err(AdeckError::TooFewColumns {
+            line: line_no,
+            found: fields.len(),
+        });
+    }
+
+    Ok(AdeckEntry {
+        basin: field(&fields, 0).to_string(),
+        storm_number: req_int(field(&fields, 1), "storm_number", line_no)?,
+        init_time: field(&fields, 2).to_string(),
+        run_id: opt_int(field(&fields, 3), "run_id", line_no)?,
+        model: field(&fields, 4).to_string(),
+        forecast_hour: req_int(field(&fields, 5), "forecast_hour", line_no)?,
+        latitude: opt_coordinate(field(&fields, 6), 'N', 'S', "latitude", line_no)?,
+        longitude: opt_coordinate(field(&fields, 7), 'E', 'W', "longitude", line_no)?,
+        wind_speed: opt_int(field(&fields, 8), "wind_speed", line_no)?,
+        pressure: opt_int(field(&fields, 9), "pressure", line_no)?,
+        storm_type: field(&fields, 10).to_string(),
+        rad: opt_int(field(&fields, 11), "rad", line_no)?,
+        windcode: field(&fields, 12).to_string(),
+        rad_neq: opt_int(field(&fields, 13), "rad_neq", line_no)?,
+        rad_seq: opt_int(field(&fields, 14), "rad_seq", line_no)?,
+        rad_swq: opt_int(field(&fields, 15), "rad_swq", line_no)?,
+        rad_nwq: opt_int(field(&fields, 16), "rad_nwq", line_no)?,
+        pouter: opt_int(field(&fields, 17), "pouter", line_no)?,
+        router: opt_int(field(&fields, 18), "router", line_no)?,
+        rmw: opt_int(field(&fields, 19), "rmw", line_no)?,
+        gusts: opt_int(field(&fields, 20), "gusts", line_no)?,
+        eye: opt_int(field(&fields, 21), "eye", line_no)?,
+        subregion: field(&fields, 22).to_string(),
+        maxseas: opt_int(field(&fields, 23), "maxseas", line_no)?,
+        initials: field(&fields, 24).to_string(),
+        direction: opt_int(field(&fields, 25), "direction", line_no)?,
+        speed: opt_int(field(&fields, 26), "speed", line_no)?,
+        stormname: field(&fields, 27).to_string(),
+        depth: field(&fields, 28).to_string(),
+        seas: opt_int(field(&fields, 29), "seas", line_no)?,
+        seascode: field(&fields, 30).to_string(),
+        seas1: opt_int(field(&fields, 31), "seas1", line_no)?,
+        seas2: opt_int(field(&fields, 32), "seas2", line_no)?,
+        seas3: opt_int(field(&fields, 33), "seas3", line_no)?,
+        seas4: opt_int(field(&fields, 34), "seas4", line_no)?,
+    })
+}
+
+/// The Arrow schema describing a parsed A-deck. The identifying columns
+/// (basin, storm number, init time, model, forecast hour) and `storm_type`
+/// are non-nullable; every optional ATCF column, latitude and longitude
+/// included, is nullable so blanks survive the round-trip as nulls.
+pub fn adeck_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("basin", DataType::Utf8, false),
+        Field::new("storm_number", DataType::Int32, false),
+        Field::new("init_time", DataType::Utf8, false),
+        Field::new("run_id", DataType::Int32, true),
+        Field::new("model", DataType::Utf8, false),
+        Field::new("forecast_hour", DataType::Int32, false),
+        Field::new("latitude", DataType::Float32, true)
+            .with_metadata(HashMap::from([("unit".to_string(), "degrees_north".to_string())])),
+        Field::new("longitude", DataType::Float32, true)
+            .with_metadata(HashMap::from([("unit".to_string(), "degrees_east".to_string())])),
+        Field::new("wind_speed", DataType::Int32, true),
+        Field::new("pressure", DataType::Int32, true),
+        Field::new("storm_type", DataType::Utf8, false),
+        Field::new("rad", DataType::Int32, true),
+        Field::new("windcode", DataType::Utf8, true),
+        Field::new("rad_neq", DataType::Int32, true),
+        Field::new("rad_seq", DataType::Int32, true),
+        Field::new("rad_swq", DataType::Int32, true),
+        Field::new("rad_nwq", DataType::Int32, true),
+        Field::new("pouter", DataType::Int32, true),
+        Field::new("router", DataType::Int32, true),
+        Field::new("rmw", DataType::Int32, true),
+        Field::new("gusts", DataType::Int32, true),
+        Field::new("eye", DataType::Int32, true),
+        Field::new("subregion", DataType::Utf8, true),
+        Field::new("maxseas", DataType::Int32, true),
+        Field::new("initials", DataType::Utf8, true),
+        Field::new("direction", DataType::Int32, true),
+        Field::new("speed", DataType::Int32, true),
+        Field::new("stormname", DataType::Utf8, true),
+        Field::new("depth", DataType::Utf8, true),
+        Field::new("seas", DataType::Int32, true),
+        Field::new("seascode", DataType::Utf8, true),
+        Field::new("seas1", DataType::Int32, true),
+        Field::new("seas2", DataType::Int32, true),
+        Field::new("seas3", DataType::Int32, true),
+        Field::new("seas4", DataType::Int32, true),
+    ]))
+}
+
+/// Build a `RecordBatch` from parsed entries by streaming them through typed
+/// Arrow builders.
+pub fn build_record_batch(entries: &[AdeckEntry]) -> Result<RecordBatch, ArrowError> {
+    let cap = entries.len();
+
+    let mut basin = StringBuilder::new();
+    let mut storm_number = Int32Builder::with_capacity(cap);
+    let mut init_time = StringBuilder::new();
+    let mut run_id = Int32Builder::with_capacity(cap);
+    let mut model = StringBuilder::new();
+    let mut forecast_hour = Int32Builder::with_capacity(cap);
+    let mut latitude = Float32Builder::with_capacity(cap);
+    let mut longitude = Float32Builder::with_capacity(cap);
+    let mut wind_speed = Int32Builder::with_capacity(cap);
+    let mut pressure = Int32Builder::with_capacity(cap);
+    let mut storm_type = StringBuilder::new();
+    let mut rad = Int32Builder::with_capacity(cap);
+    let mut windcode = StringBuilder::new();
+    let mut rad_neq = Int32Builder::with_capacity(cap);
+    let mut rad_seq = Int32Builder::with_capacity(cap);
+    let mut rad_swq = Int32Builder::with_capacity(cap);
+    let mut rad_nwq = Int32Builder::with_capacity(cap);
+    let mut pouter = Int32Builder::with_capacity(cap);
+    let mut router = Int32Builder::with_capacity(cap);
+    let mut rmw = Int32Builder::with_capacity(cap);
+    let mut gusts = Int32Builder::with_capacity(cap);
+    let mut eye = Int32Builder::with_capacity(cap);
+    let mut subregion = StringBuilder::new();
+    let mut maxseas = Int32Builder::with_capacity(cap);
+    let mut initials = StringBuilder::new();
+    let mut direction = Int32Builder::with_capacity(cap);
+    let mut speed = Int32Builder::with_capacity(cap);
+    let mut stormname = StringBuilder::new();
+    let mut depth = StringBuilder::new();
+    let mut seas = Int32Builder::with_capacity(cap);
+    let mut seascode = StringBuilder::new();
+    let mut seas1 = Int32Builder::with_capacity(cap);
+    let mut seas2 = Int32Builder::with_capacity(cap);
+    let mut seas3 = Int32Builder::with_capacity(cap);
+    let mut seas4 = Int32Builder::with_capacity(cap);
+
+    for e in entries {
+        basin.append_value(&e.basin);
+        storm_number.append_value(e.storm_number);
+        init_time.append_value(&e.init_time);
+        run_id.append_option(e.run_id);
+        model.append_value(&e.model);
+        forecast_hour.append_value(e.forecast_hour);
+        latitude.append_option(e.latitude);
+        longitude.append_option(e.longitude);
+        wind_speed.append_option(e.wind_speed);
+        pressure.append_option(e.pressure);
+        storm_type.append_value(&e.storm_type);
+        rad.append_option(e.rad);
+        windcode.append_option(non_empty(&e.windcode));
+        rad_neq.append_option(e.rad_neq);
+        rad_seq.append_option(e.rad_seq);
+        rad_swq.append_option(e.rad_swq);
+        rad_nwq.append_option(e.rad_nwq);
+        pouter.append_option(e.pouter);
+        router.append_option(e.router);
+        rmw.append_option(e.rmw);
+        gusts.append_option(e.gusts);
+        eye.append_option(e.eye);
+        subregion.append_option(non_empty(&e.subregion));
+        maxseas.append_option(e.maxseas);
+        initials.append_option(non_empty(&e.initials));
+        direction.append_option(e.direction);
+        speed.append_option(e.speed);
+        stormname.append_option(non_empty(&e.stormname));
+        depth.append_option(non_empty(&e.depth));
+        seas.append_option(e.seas);
+        seascode.append_option(non_empty(&e.seascode));
+        seas1.append_option(e.seas1);
+        seas2.append_option(e.seas2);
+        seas3.append_option(e.seas3);
+        seas4.append_option(e.seas4);
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(basin.finish()),
+        Arc::new(storm_number.finish()),
+        Arc::new(init_time.finish()),
+        Arc::new(run_id.finish()),
+        Arc::new(model.finish()),
+        Arc::new(forecast_hour.finish()),
+        Arc::new(latitude.finish()),
+        Arc::new(longitude.finish()),
+        Arc::new(wind_speed.finish()),
+        Arc::new(pressure.finish()),
+        Arc::new(storm_type.finish()),
+        Arc::new(rad.finish()),
+        Arc::new(windcode.finish()),
+        Arc::new(rad_neq.finish()),
+        Arc::new(rad_seq.finish()),
+        Arc::new(rad_swq.finish()),
+        Arc::new(rad_nwq.finish()),
+        Arc::new(pouter.finish()),
+        Arc::new(router.finish()),
+        Arc::new(rmw.finish()),
+        Arc::new(gusts.finish()),
+        Arc::new(eye.finish()),
+        Arc::new(subregion.finish()),
+        Arc::new(maxseas.finish()),
+        Arc::new(initials.finish()),
+        Arc::new(direction.finish()),
+        Arc::new(speed.finish()),
+        Arc::new(stormname.finish()),
+        Arc::new(depth.finish()),
+        Arc::new(seas.finish()),
+        Arc::new(seascode.finish()),
+        Arc::new(seas1.finish()),
+        Arc::new(seas2.finish()),
+        Arc::new(seas3.finish()),
+        Arc::new(seas4.finish()),
+    ];
+
+    RecordBatch::try_new(adeck_schema(), columns)
+}
+
+/// Map an empty string to a null, otherwise keep the value. ATCF pads trailing
+/// metadata columns with blanks that read more naturally as nulls.
+fn non_empty(s: &str) -> Option<&str> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+/// An ATCF deck variant. A-deck carries model forecasts, B-deck the
+/// best-track analysis, and F-deck aircraft/satellite fix records.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Deck {
+    A,
+    B,
+    F,
+}
+
+impl Deck {
+    /// Infer the deck from an ATCF filename (`aal012023.dat`, `bep04…`,
+    /// `fwp15…`). Returns `None` when the leading letter is not recognised.
+    pub fn from_filename(path: &str) -> Option<Deck> {
+        let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        match name.bytes().next().map(|b| b.to_ascii_lowercase()) {
+            Some(b'a') => Some(Deck::A),
+            Some(b'b') => Some(Deck::B),
+            Some(b'f') => Some(Deck::F),
+            _ => None,
+        }
+    }
+
+    /// Resolve the deck for `path`: an explicit override wins, otherwise the
+    /// filename is sniffed, falling back to A-deck.
+    pub fn resolve(path: &str, explicit: Option<Deck>) -> Deck {
+        explicit
+            .or_else(|| Deck::from_filename(path))
+            .unwrap_or(Deck::A)
+    }
+
+}
+
+/// One parsed ATCF F-deck fix record. The F-deck layout differs from the
+/// forecast decks: it leads with the fix format and type and stores
+/// coordinates in hundredths of a degree.
+///
+/// Comma-separated column layout (0-indexed), per the ATCF fix-record format:
+///
+/// | idx | column              | field               |
+/// |-----|---------------------|---------------------|
+/// | 0   | BASIN               | `basin`             |
+/// | 1   | CY                  | `storm_number`      |
+/// | 2   | DTG                 | `fix_time`          |
+/// | 3   | FIXFORMAT           | `fix_format`        |
+/// | 4   | FIXTYPE             | `fix_type`          |
+/// | 5   | CI                  | not modelled        |
+/// | 6   | FLAGGED             | not modelled        |
+/// | 7   | LAT                 | `latitude`          |
+/// | 8   | LON                 | `longitude`         |
+/// | 9   | HEIGHT              | `height`            |
+/// | 10  | POSITION CONFIDENCE | `position_confidence` |
+/// | 11  | WIND SPEED          | `wind_speed`         |
+/// | 12  | PRESSURE            | `pressure`          |
+#[derive(Debug)]
+pub struct FdeckEntry {
+    pub basin: String,
+    pub storm_number: i32,
+    pub fix_time: String,
+    pub fix_format: Option<i32>,
+    pub fix_type: String,
+    pub latitude: Option<f32>,
+    pub longitude: Option<f32>,
+    pub height: Option<i32>,
+    pub position_confidence: Option<i32>,
+    pub wind_speed: Option<i32>,
+    pub pressure: Option<i32>,
+}
+
+/// Parse a single ATCF F-deck fix line. See [`FdeckEntry`] for the column map.
+pub fn parse_fdeck_line(line: &str, line_no: usize) -> Result<FdeckEntry, AdeckError> {
     let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
-    if fields.len() < 24 {
-        return None; // Skip malformed lines
-    }
-
-    Some(AdeckEntry {
-        basin: fields[0].to_string(),
-        storm_number: fields[1].parse().unwrap_or(0),
-        init_time: fields[2].to_string(),
-        run_id: fields[3].parse().unwrap_or(0),
-        model: fields[4].to_string(),
-        forecast_hour: fields[5].parse().unwrap_or(0),
-        latitude: fields[6].to_string(),
-        longitude: fields[7].to_string(),
-        wind_speed: fields[8].parse().unwrap_or(0),
-        pressure: fields[9].parse().ok(),
-        storm_type: fields[10].to_string(),
-        radii_34: vec![
-            fields[12].parse().unwrap_or(0),
-            fields[13].parse().unwrap_or(0),
-            fields[14].parse().unwrap_or(0),
-            fields[15].parse().unwrap_or(0),
-        ],
-        radii_50: vec![
-            fields[16].parse().unwrap_or(0),
-            fields[17].parse().unwrap_or(0),
-            fields[18].parse().unwrap_or(0),
-            fields[19].parse().unwrap_or(0),
-        ],
-        radii_64: vec![
-            fields[20].parse().unwrap_or(0),
-            fields[21].parse().unwrap_or(0),
-            fields[22].parse().unwrap_or(0),
-            fields[23].parse().unwrap_or(0),
-        ],
-        metadata: fields[24..].iter().map(|&f| f.to_string()).collect(),
+    if fields.len() < 13 {
+        return Err(AdeckError::TooFewColumns {
+            line: line_no,
+            found: fields.len(),
+        });
+    }
+
+    Ok(FdeckEntry {
+        basin: field(&fields, 0).to_string(),
+        storm_number: req_int(field(&fields, 1), "storm_number", line_no)?,
+        fix_time: field(&fields, 2).to_string(),
+        fix_format: opt_int(field(&fields, 3), "fix_format", line_no)?,
+        fix_type: field(&fields, 4).to_string(),
+        latitude: {
+            let t = field(&fields, 7);
+            let v = parse_coordinate_scaled(t, 'N', 'S', 100.0);
+            if v.is_none() && !is_blank(t) {
+                return Err(AdeckError::Parse {
+                    line: line_no,
+                    column: "latitude",
+                    value: t.to_string(),
+                });
+            }
+            v
+        },
+        longitude: {
+            let t = field(&fields, 8);
+            let v = parse_coordinate_scaled(t, 'E', 'W', 100.0);
+            if v.is_none() && !is_blank(t) {
+                return Err(AdeckError::Parse {
+                    line: line_no,
+                    column: "longitude",
+                    value: t.to_string(),
+                });
+            }
+            v
+        },
+        height: opt_int(field(&fields, 9), "height", line_no)?,
+        position_confidence: opt_int(field(&fields, 10), "position_confidence", line_no)?,
+        wind_speed: opt_int(field(&fields, 11), "wind_speed", line_no)?,
+        pressure: opt_int(field(&fields, 12), "pressure", line_no)?,
     })
 }
 
-fn read_adeck_file(filepath: &str) -> Vec<AdeckEntry> {
-    let file = File::open(filepath).expect("Unable to open file");
+/// The Arrow schema describing a parsed F-deck.
+pub fn fdeck_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("basin", DataType::Utf8, false),
+        Field::new("storm_number", DataType::Int32, false),
+        Field::new("fix_time", DataType::Utf8, false),
+        Field::new("fix_format", DataType::Int32, true),
+        Field::new("fix_type", DataType::Utf8, false),
+        Field::new("latitude", DataType::Float32, true)
+            .with_metadata(HashMap::from([("unit".to_string(), "degrees_north".to_string())])),
+        Field::new("longitude", DataType::Float32, true)
+            .with_metadata(HashMap::from([("unit".to_string(), "degrees_east".to_string())])),
+        Field::new("height", DataType::Int32, true),
+        Field::new("position_confidence", DataType::Int32, true),
+        Field::new("wind_speed", DataType::Int32, true),
+        Field::new("pressure", DataType::Int32, true),
+    ]))
+}
+
+/// Build an F-deck `RecordBatch` from parsed entries.
+pub fn build_fdeck_batch(entries: &[FdeckEntry]) -> Result<RecordBatch, ArrowError> {
+    let cap = entries.len();
+    let mut basin = StringBuilder::new();
+    let mut storm_number = Int32Builder::with_capacity(cap);
+    let mut fix_time = StringBuilder::new();
+    let mut fix_format = Int32Builder::with_capacity(cap);
+    let mut fix_type = StringBuilder::new();
+    let mut latitude = Float32Builder::with_capacity(cap);
+    let mut longitude = Float32Builder::with_capacity(cap);
+    let mut height = Int32Builder::with_capacity(cap);
+    let mut position_confidence = Int32Builder::with_capacity(cap);
+    let mut wind_speed = Int32Builder::with_capacity(cap);
+    let mut pressure = Int32Builder::with_capacity(cap);
+
+    for e in entries {
+        basin.append_value(&e.basin);
+        storm_number.append_value(e.storm_number);
+        fix_time.append_value(&e.fix_time);
+        fix_format.append_option(e.fix_format);
+        fix_type.append_value(&e.fix_type);
+        latitude.append_option(e.latitude);
+        longitude.append_option(e.longitude);
+        height.append_option(e.height);
+        position_confidence.append_option(e.position_confidence);
+        wind_speed.append_option(e.wind_speed);
+        pressure.append_option(e.pressure);
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(basin.finish()),
+        Arc::new(storm_number.finish()),
+        Arc::new(fix_time.finish()),
+        Arc::new(fix_format.finish()),
+        Arc::new(fix_type.finish()),
+        Arc::new(latitude.finish()),
+        Arc::new(longitude.finish()),
+        Arc::new(height.finish()),
+        Arc::new(position_confidence.finish()),
+        Arc::new(wind_speed.finish()),
+        Arc::new(pressure.finish()),
+    ];
+
+    RecordBatch::try_new(fdeck_schema(), columns)
+}
+
+/// The parsed batch together with any rows that failed in lenient mode.
+pub struct ParseOutcome {
+    pub batch: RecordBatch,
+    pub errors: Vec<AdeckError>,
+}
+
+/// Memory-map `filepath` and parse it into a single `RecordBatch` in parallel.
+///
+/// The file is mapped once, split on newline byte offsets, and the resulting
+/// line slices are fed to `rayon` in contiguous chunks. Each worker fills its
+/// own set of Arrow builders into a partial `RecordBatch`; the partials are
+/// concatenated at the end with [`arrow::compute::concat_batches`]. Operational
+/// A-deck archives concatenate thousands of models, so this keeps the whole
+/// file off the line-by-line single-thread path.
+///
+/// `max_threads` caps the `rayon` pool; `None` uses the global default. In
+/// `strict` mode the first malformed row aborts the whole parse; otherwise bad
+/// rows are skipped and collected into [`ParseOutcome::errors`].
+pub fn read_adeck_file_parallel(
+    filepath: &str,
+    max_threads: Option<usize>,
+    strict: bool,
+) -> Result<ParseOutcome, AdeckError> {
+    let file = File::open(filepath)
+        .map_err(|e| AdeckError::Io(format!("Unable to open {filepath}"), e))?;
+    // `Mmap::map` errors on a zero-length file on Linux; a legitimately empty
+    // deck should yield an empty batch, not an I/O error.
+    let len = file
+        .metadata()
+        .map_err(|e| AdeckError::Io(format!("Unable to stat {filepath}"), e))?
+        .len();
+    if len == 0 {
+        return Ok(ParseOutcome {
+            batch: build_record_batch(&[])?,
+            errors: Vec::new(),
+        });
+    }
+    // SAFETY: the mapping is read-only and dropped before this function returns.
+    let mmap = unsafe { Mmap::map(&file) }
+        .map_err(|e| AdeckError::Io(format!("Unable to mmap {filepath}"), e))?;
+
+    // Pair each line with its 1-based source number before fanning out.
+    let lines: Vec<(usize, &[u8])> = mmap
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .map(|(i, raw)| (i + 1, raw))
+        .collect();
+    // Chunk the line index space so each worker sees a contiguous slice.
+    let threads = max_threads.unwrap_or_else(rayon::current_num_threads).max(1);
+    let chunk_size = lines.len().div_ceil(threads).max(1);
+
+    type ChunkResult = (RecordBatch, Vec<AdeckError>);
+    let parse_chunk = |chunk: &[(usize, &[u8])]| -> Result<ChunkResult, AdeckError> {
+        let mut entries = Vec::with_capacity(chunk.len());
+        let mut errors = Vec::new();
+        for &(line_no, raw) in chunk {
+            let text = match std::str::from_utf8(raw) {
+                Ok(t) if t.trim().is_empty() => continue,
+                Ok(t) => t,
+                Err(_) => {
+                    let e = AdeckError::Parse {
+                        line: line_no,
+                        column: "<utf8>",
+                        value: String::from_utf8_lossy(raw).into_owned(),
+                    };
+                    if strict {
+                        return Err(e);
+                    }
+                    errors.push(e);
+                    continue;
+                }
+            };
+            match parse_adeck_line(text, line_no) {
+                Ok(entry) => entries.push(entry),
+                Err(e) if strict => return Err(e),
+                Err(e) => errors.push(e),
+            }
+        }
+        Ok((build_record_batch(&entries)?, errors))
+    };
+
+    // Collect every chunk's outcome without short-circuiting: `par_chunks`
+    // resolves chunks in whatever order rayon schedules them, so bailing out
+    // on the first `Err` a naive `collect::<Result<Vec<_>, _>>()` sees would
+    // make strict-mode line attribution nondeterministic across runs.
+    let chunk_results: Vec<Result<ChunkResult, AdeckError>> = if let Some(n) = max_threads {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .map_err(|e| AdeckError::Arrow(ArrowError::ExternalError(Box::new(e))))?;
+        pool.install(|| lines.par_chunks(chunk_size).map(parse_chunk).collect())
+    } else {
+        lines.par_chunks(chunk_size).map(parse_chunk).collect()
+    };
+
+    if strict {
+        if let Some(idx) = chunk_results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.as_ref().err().map(|e| (i, e.line().unwrap_or(0))))
+            .min_by_key(|&(_, line)| line)
+            .map(|(i, _)| i)
+        {
+            return Err(chunk_results.into_iter().nth(idx).unwrap().unwrap_err());
+        }
+    }
+
+    let chunks: Vec<ChunkResult> = chunk_results.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    let mut batches = Vec::with_capacity(chunks.len());
+    let mut errors = Vec::new();
+    for (batch, chunk_errors) in chunks {
+        batches.push(batch);
+        errors.extend(chunk_errors);
+    }
+
+    Ok(ParseOutcome {
+        batch: concat_batches(&adeck_schema(), &batches)?,
+        errors,
+    })
+}
+
+/// Read a file line by line, applying `parse` to each non-blank line.
+///
+/// In `strict` mode the first failure aborts; otherwise failures are collected
+/// and the offending rows skipped.
+fn read_lines_parsed<T>(
+    filepath: &str,
+    strict: bool,
+    mut parse: impl FnMut(&str, usize) -> Result<T, AdeckError>,
+) -> Result<(Vec<T>, Vec<AdeckError>), AdeckError> {
+    let file = File::open(filepath)
+        .map_err(|e| AdeckError::Io(format!("Unable to open {filepath}"), e))?;
     let reader = BufReader::new(file);
 
-    reader
-        .lines()
-        .filter_map(|line| line.ok())
-        .filter_map(|line| parse_adeck_line(&line))
-        .collect()
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| AdeckError::Io(filepath.to_string(), e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse(&line, idx + 1) {
+            Ok(entry) => entries.push(entry),
+            Err(e) if strict => return Err(e),
+            Err(e) => errors.push(e),
+        }
+    }
+    Ok((entries, errors))
+}
+
+/// Row-identity key for an A-deck/B-deck entry, used to drop duplicate rows
+/// when merging per-model files. The storm is identified by basin +
+/// storm_number + init_time; model, forecast hour and wind radius distinguish
+/// individual rows. No other column is compared, so two rows with the same key
+/// count as duplicates even when their other values differ.
+fn adeck_key(e: &AdeckEntry) -> String {
+    format!(
+        "{}|{}|{}|{}|{}|{:?}",
+        e.basin, e.storm_number, e.init_time, e.model, e.forecast_hour, e.rad
+    )
+}
+
+/// Row-identity key for an F-deck fix record: basin, storm number, fix time
+/// and fix type.
+fn fdeck_key(e: &FdeckEntry) -> String {
+    format!(
+        "{}|{}|{}|{}",
+        e.basin, e.storm_number, e.fix_time, e.fix_type
+    )
+}
+
+/// Parse one or more ATCF files of the same `deck` into a single deduplicated
+/// `RecordBatch`.
+///
+/// This is the common case when assembling a full season: per-model A-deck
+/// files for the same storm are concatenated, and a row whose identity key
+/// (see [`adeck_key`] / [`fdeck_key`]) was already seen is dropped, keeping
+/// the first one read, so reruns or overlapping archives don't double-count.
+pub fn read_decks(
+    paths: &[String],
+    deck: Deck,
+    strict: bool,
+) -> Result<ParseOutcome, AdeckError> {
+    let mut errors = Vec::new();
+
+    match deck {
+        // B-deck best-track files share the A-deck column layout — they differ
+        // in content (analysed positions rather than model forecasts) but not
+        // in structure — so B-deck intentionally reuses [`adeck_schema`] and
+        // [`parse_adeck_line`]. The shared parser tolerates the blank TECHNUM
+        // that every BEST-track line carries (see [`AdeckEntry::run_id`]).
+        Deck::A | Deck::B => {
+            let mut seen = HashSet::new();
+            let mut entries = Vec::new();
+            for path in paths {
+                let (parsed, errs) = read_lines_parsed(path, strict, parse_adeck_line)?;
+                errors.extend(errs);
+                for entry in parsed {
+                    if seen.insert(adeck_key(&entry)) {
+                        entries.push(entry);
+                    }
+                }
+            }
+            Ok(ParseOutcome {
+                batch: build_record_batch(&entries)?,
+                errors,
+            })
+        }
+        Deck::F => {
+            let mut seen = HashSet::new();
+            let mut entries = Vec::new();
+            for path in paths {
+                let (parsed, errs) = read_lines_parsed(path, strict, parse_fdeck_line)?;
+                errors.extend(errs);
+                for entry in parsed {
+                    if seen.insert(fdeck_key(&entry)) {
+                        entries.push(entry);
+                    }
+                }
+            }
+            Ok(ParseOutcome {
+                batch: build_fdeck_batch(&entries)?,
+                errors,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_coordinate_decodes_tenths_of_a_degree() {
+        // 261N = 26.1N, not 261.0 -- the whole point of this parser.
+        assert_eq!(parse_coordinate("261N", 'N', 'S'), Some(26.1));
+        assert_eq!(parse_coordinate("0805W", 'E', 'W'), Some(-80.5));
+    }
+
+    #[test]
+    fn parse_coordinate_blank_tokens_are_none() {
+        assert_eq!(parse_coordinate("", 'N', 'S'), None);
+        assert_eq!(parse_coordinate("****", 'N', 'S'), None);
+    }
+
+    #[test]
+    fn parse_fdeck_line_maps_columns_per_the_documented_layout() {
+        // AL, CY, DTG, FIXFORMAT, FIXTYPE, CI, FLAGGED, LAT, LON, HEIGHT,
+        // POSITION CONFIDENCE, WIND SPEED, PRESSURE -- see the FdeckEntry
+        // column map. CI and FLAGGED are both present and unmodelled, so
+        // getting their count wrong would shift every field from latitude on.
+        let line = "AL, 09, 202008150600, 01, DVTS, 1, 0, 261N, 0805W, 0, 1, 55, 990";
+        let entry = parse_fdeck_line(line, 1).unwrap();
+        assert_eq!(entry.basin, "AL");
+        assert_eq!(entry.storm_number, 9);
+        assert_eq!(entry.latitude, Some(2.61));
+        assert_eq!(entry.longitude, Some(-8.05));
+        assert_eq!(entry.height, Some(0));
+        assert_eq!(entry.position_confidence, Some(1));
+        assert_eq!(entry.wind_speed, Some(55));
+        assert_eq!(entry.pressure, Some(990));
+    }
+
+    #[test]
+    fn strict_parallel_parse_reports_the_lowest_failing_line() {
+        // Two malformed rows land in two different rayon chunks (max_threads
+        // = 2 over 4 lines); the reported line must be the earliest one
+        // regardless of which chunk rayon happens to resolve first.
+        let path = std::env::temp_dir().join(format!(
+            "adeck_parser_strict_test_{}.txt",
+            std::process::id()
+        ));
+        let contents = "bad\n\
+                         AL, 01, 2020081500, , GFSO, 0, 261N, 0805W, 35, 990,TS\n\
+                         bad\n\
+                         AL, 01, 2020081506, , GFSO, 6, 262N, 0806W, 35, 990,TS";
+        std::fs::write(&path, contents).unwrap();
+
+        let result = read_adeck_file_parallel(path.to_str().unwrap(), Some(2), true);
+        std::fs::remove_file(&path).unwrap();
+
+        match result {
+            Err(e) => assert_eq!(e.line(), Some(1)),
+            Ok(_) => panic!("expected a strict-mode parse error"),
+        }
+    }
 }
@@ -1,152 +1,62 @@
-use std::collections::HashMap;
+mod adeck_parser;
+
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::sync::{Mutex, Arc};
+use std::sync::Arc;
 use pyo3::prelude::*;
-use pyo3::types::{IntoPyDict, PyDict};
-use serde::Serialize;
 use arrow::record_batch::RecordBatch;
 use arrow::pyarrow::PyArrowConvert;
 use arrow::array::{ArrayRef, Float64Array, StringArray, TimestampNanosecondArray, StringBuilder, Float64Builder, TimestampNanosecondBuilder};
+use arrow::compute::concat_batches;
 use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::error::ArrowError;
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
 use pyo3::wrap_pyfunction;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use polars::prelude::{DataFrame, PolarsError, Series};
+use pyo3_polars::PyDataFrame;
 
+use crate::adeck_parser::{read_adeck_file_parallel, read_decks, AdeckError, Deck};
 
+create_exception!(adeck_parser, AdeckParseError, PyException);
 
-#[derive(Serialize)]
-enum Value {
-    Str(String),
-    Int(i32),
-    Float(f32),
+impl From<AdeckError> for PyErr {
+    fn from(e: AdeckError) -> Self {
+        match e {
+            AdeckError::Io(..) => PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()),
+            _ => AdeckParseError::new_err(e.to_string()),
+        }
+    }
 }
 
-// #[pyfunction]
-// fn parse_adeck(filepath: &str, py: Python) -> PyResult<PyObject> {
-//     let file = File::open(filepath).expect("Unable to open file");
-//     let reader = BufReader::new(file);
-
-//     let result = Mutex::new(HashMap::new());
-//     {
-//         let mut result = result.lock().unwrap();
-//         result.insert("basin".to_string(), Vec::new());
-//         result.insert("storm_number".to_string(), Vec::new());
-//         result.insert("init_time".to_string(), Vec::new());
-//         result.insert("run_id".to_string(), Vec::new());
-//         result.insert("model".to_string(), Vec::new());
-//         result.insert("forecast_hour".to_string(), Vec::new());
-//         result.insert("latitude".to_string(), Vec::new());
-//         result.insert("longitude".to_string(), Vec::new());
-//         result.insert("wind_speed".to_string(), Vec::new());
-//         result.insert("pressure".to_string(), Vec::new());
-//         result.insert("storm_type".to_string(), Vec::new());
-//         result.insert("rad".to_string(), Vec::new());
-//         result.insert("windcode".to_string(), Vec::new());
-//         result.insert("rad_NEQ".to_string(), Vec::new());
-//         result.insert("rad_SEQ".to_string(), Vec::new());
-//         result.insert("rad_SWQ".to_string(), Vec::new());
-//         result.insert("rad_NWQ".to_string(), Vec::new());
-//         result.insert("pouter".to_string(), Vec::new());
-//         result.insert("router".to_string(), Vec::new());
-//         result.insert("rmw".to_string(), Vec::new());
-//         result.insert("gusts".to_string(), Vec::new());
-//         result.insert("eye".to_string(), Vec::new());
-//         result.insert("subregion".to_string(), Vec::new());
-//         result.insert("maxseas".to_string(), Vec::new());
-//         result.insert("initials".to_string(), Vec::new());
-//         result.insert("direction".to_string(), Vec::new());
-//         result.insert("speed".to_string(), Vec::new());
-//         result.insert("stormname".to_string(), Vec::new());
-//         result.insert("depth".to_string(), Vec::new());
-//         result.insert("seas".to_string(), Vec::new());
-//         result.insert("seascode".to_string(), Vec::new());
-//         result.insert("seas1".to_string(), Vec::new());
-//         result.insert("seas2".to_string(), Vec::new());
-//         result.insert("seas3".to_string(), Vec::new());
-//         result.insert("seas4".to_string(), Vec::new());
-//         result.insert("userdefined1".to_string(), Vec::new());
-//         result.insert("userdata1".to_string(), Vec::new());
-//         result.insert("userdefined2".to_string(), Vec::new());
-//         result.insert("userdata2".to_string(), Vec::new());
-//         result.insert("userdefined3".to_string(), Vec::new());
-//         result.insert("userdata3".to_string(), Vec::new());
-//         result.insert("userdefined4".to_string(), Vec::new());
-//         result.insert("userdata4".to_string(), Vec::new());
-//         result.insert("userdefined5".to_string(), Vec::new());
-//         result.insert("userdata5".to_string(), Vec::new());
-//     }
-
-//     let lines: Vec<String> = reader.lines().filter_map(|line| line.ok()).collect();
-//     for line in lines.iter() {
-//         let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
-
-//         let latitude = parse_coordinate(fields.get(6).unwrap_or(&""), 'N', 'S');
-//         let longitude = parse_coordinate(fields.get(7).unwrap_or(&""), 'E', 'W');
-
-//         let mut result = result.lock().unwrap(); // Ensure mutable access to result
-
-//         result.get_mut("basin").unwrap().push(Value::Str(fields.get(0).unwrap_or(&"").to_string()));
-//         result.get_mut("storm_number").unwrap().push(Value::Int(fields.get(1).unwrap_or(&"0").parse().unwrap_or(0)));
-//         result.get_mut("init_time").unwrap().push(Value::Str(fields.get(2).unwrap_or(&"").to_string()));
-//         result.get_mut("run_id").unwrap().push(Value::Int(fields.get(3).unwrap_or(&"0").parse().unwrap_or(0)));
-//         result.get_mut("model").unwrap().push(Value::Str(fields.get(4).unwrap_or(&"").to_string()));
-//         result.get_mut("forecast_hour").unwrap().push(Value::Int(fields.get(5).unwrap_or(&"0").parse().unwrap_or(0)));
-//         result.get_mut("latitude").unwrap().push(Value::Float(latitude.unwrap_or(0.0)));
-//         result.get_mut("longitude").unwrap().push(Value::Float(longitude.unwrap_or(0.0)));
-//         result.get_mut("wind_speed").unwrap().push(Value::Int(fields.get(8).unwrap_or(&"0").parse().unwrap_or(0)));
-//         result.get_mut("pressure").unwrap().push(Value::Int(fields.get(9).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("storm_type").unwrap().push(Value::Str(fields.get(10).unwrap_or(&"").to_string()));
-//         // result.get_mut("rad").unwrap().push(Value::Str(fields.get(11).unwrap_or(&"").to_string()));
-//         // result.get_mut("windcode").unwrap().push(Value::Str(fields.get(12).unwrap_or(&"").to_string()));
-//         // result.get_mut("rad_NEQ").unwrap().push(Value::Int(fields.get(13).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("rad_SEQ").unwrap().push(Value::Int(fields.get(14).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("rad_SWQ").unwrap().push(Value::Int(fields.get(15).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("rad_NWQ").unwrap().push(Value::Int(fields.get(16).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("pouter").unwrap().push(Value::Int(fields.get(17).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("router").unwrap().push(Value::Int(fields.get(18).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("rmw").unwrap().push(Value::Int(fields.get(19).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("gusts").unwrap().push(Value::Int(fields.get(20).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("eye").unwrap().push(Value::Int(fields.get(21).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("subregion").unwrap().push(Value::Str(fields.get(22).unwrap_or(&"").to_string()));
-//         // result.get_mut("maxseas").unwrap().push(Value::Int(fields.get(23).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("initials").unwrap().push(Value::Str(fields.get(24).unwrap_or(&"").to_string()));
-//         // result.get_mut("direction").unwrap().push(Value::Int(fields.get(25).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("speed").unwrap().push(Value::Int(fields.get(26).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("stormname").unwrap().push(Value::Str(fields.get(27).unwrap_or(&"").to_string()));
-//         // result.get_mut("depth").unwrap().push(Value::Str(fields.get(28).unwrap_or(&"").to_string()));
-//         // result.get_mut("seas").unwrap().push(Value::Int(fields.get(29).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("seascode").unwrap().push(Value::Str(fields.get(30).unwrap_or(&"").to_string()));
-//         // result.get_mut("seas1").unwrap().push(Value::Int(fields.get(31).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("seas2").unwrap().push(Value::Int(fields.get(32).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("seas3").unwrap().push(Value::Int(fields.get(33).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("seas4").unwrap().push(Value::Int(fields.get(34).unwrap_or(&"0").parse().unwrap_or(0)));
-//         // result.get_mut("userdefined1").unwrap().push(Value::Str(fields.get(35).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdata1").unwrap().push(Value::Str(fields.get(36).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdefined2").unwrap().push(Value::Str(fields.get(37).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdata2").unwrap().push(Value::Str(fields.get(38).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdefined3").unwrap().push(Value::Str(fields.get(39).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdata3").unwrap().push(Value::Str(fields.get(40).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdefined4").unwrap().push(Value::Str(fields.get(41).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdata4").unwrap().push(Value::Str(fields.get(42).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdefined5").unwrap().push(Value::Str(fields.get(43).unwrap_or(&"").to_string()));
-//         // result.get_mut("userdata5").unwrap().push(Value::Str(fields.get(44).unwrap_or(&"").to_string()));
-//     }
-
-//     let result = result.lock().unwrap();
-//     let py_dict = result.into_py_dict(py);
-//     Ok(py_dict.into())
-// }
-
-fn parse_coordinate(coord: &str, pos: char, neg: char) -> Option<f32> {
-    if coord.is_empty() {
-        return None;
-    }
-    let last_char = coord.chars().last().unwrap();
-    let value: f32 = coord[..coord.len() - 1].parse().ok()?;
-    match last_char {
-        c if c == pos => Some(value),
-        c if c == neg => Some(-value),
-        _ => None,
-    }
+
+/// Parse an ATCF A-deck file and return the result as a PyArrow `Table`.
+///
+/// The file is memory-mapped and parsed in parallel. `max_threads` caps the
+/// worker pool; leave it unset to use all available cores. Set `as_polars` to
+/// get a native `polars.DataFrame` instead of a PyArrow `Table`.
+///
+/// Returns a `(data, errors)` tuple. In `strict` mode the first malformed row
+/// raises `AdeckParseError`; otherwise bad rows are skipped and reported as a
+/// list of error strings in `errors`.
+#[pyfunction]
+#[pyo3(signature = (filepath, max_threads = None, as_polars = false, strict = false))]
+fn parse_adeck(
+    filepath: &str,
+    max_threads: Option<usize>,
+    as_polars: bool,
+    strict: bool,
+    py: Python,
+) -> PyResult<PyObject> {
+    let outcome = read_adeck_file_parallel(filepath, max_threads, strict)?;
+    let data = if as_polars {
+        record_batch_to_polars(&outcome.batch, py)?
+    } else {
+        outcome.batch.to_pyarrow(py)?
+    };
+    let errors: Vec<String> = outcome.errors.iter().map(AdeckError::to_string).collect();
+    Ok((data, errors).into_py(py))
 }
 
 
@@ -233,33 +143,234 @@ impl ArrowDataFrame {
         Ok(())
     }
 
-    /// Convert to PyArrow Table
-    pub fn to_pyarrow(&mut self, py: Python) -> PyResult<PyObject> {
-        // Finalize the Arrow arrays
+    /// Finalize the builders into a single RecordBatch.
+    fn finish_batch(&mut self) -> RecordBatch {
         let array1: ArrayRef = Arc::new(self.column1.finish());
         let array2: ArrayRef = Arc::new(self.column2.finish());
         let array3: ArrayRef = Arc::new(self.column3.finish());
         let array4: ArrayRef = Arc::new(self.column4.finish());
 
-        // Create a RecordBatch
-        let batch = RecordBatch::try_new(
+        RecordBatch::try_new(
             self.schema.clone(),
             vec![array1, array2, array3, array4],
         )
-        .expect("Failed to create RecordBatch");
+        .expect("Failed to create RecordBatch")
+    }
 
-        // Convert RecordBatch to PyArrow Table
+    /// Convert to PyArrow Table
+    pub fn to_pyarrow(&mut self, py: Python) -> PyResult<PyObject> {
+        let batch = self.finish_batch();
         Ok(batch.to_pyarrow(py).expect("Failed to convert to PyArrow Table"))
     }
+
+    /// Persist the contents to an Arrow IPC / Feather file on disk.
+    pub fn write_ipc(&mut self, filepath: &str) -> PyResult<()> {
+        let batch = self.finish_batch();
+        write_batch_ipc(&batch, filepath)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+    }
+
+    /// Read an Arrow IPC / Feather file back into a PyArrow Table.
+    #[staticmethod]
+    pub fn read_ipc(filepath: &str, py: Python) -> PyResult<PyObject> {
+        let batch = read_batch_ipc(filepath)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+        batch.to_pyarrow(py)
+    }
+
+    /// Export the contents to a native `polars.DataFrame` via the Arrow C data
+    /// interface, avoiding a PyArrow round-trip.
+    pub fn to_polars(&mut self, py: Python) -> PyResult<PyObject> {
+        let batch = self.finish_batch();
+        record_batch_to_polars(&batch, py)
+    }
+}
+
+/// Move a `RecordBatch` into Python as a `polars.DataFrame`.
+///
+/// Each Arrow column is handed to `polars` through the Arrow C data interface,
+/// so the buffers are shared rather than copied.
+fn record_batch_to_polars(batch: &RecordBatch, py: Python) -> PyResult<PyObject> {
+    let df = record_batch_to_dataframe(batch)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+    Ok(PyDataFrame(df).into_py(py))
+}
+
+// The cross-crate hand-off below reinterprets arrow-rs's C-data-interface
+// structs as polars-arrow's. Both are `#[repr(C)]` renderings of the same
+// Arrow C Data Interface spec, so the layouts must match — but that identity
+// is unchecked at the type level and a dependency bump could silently break it
+// (heap corruption, not a compile error). Pin it with static layout assertions
+// so a divergence fails the build instead.
+//
+// These assertions only cover size/align, not per-field offsets: both
+// `polars_arrow::ffi::ArrowArray` and `ArrowSchema` declare their fields
+// `pub(super)`, so an offset-of check against them can't compile outside
+// that crate. `polars_roundtrip_preserves_shape_and_values` below is the
+// complement — it exercises both a fixed-width and a variable-length
+// (`Utf8`) column, so a field-order divergence that kept size/align intact
+// would still show up as wrong values or buffer offsets in that test.
+const _: () = {
+    assert!(
+        std::mem::size_of::<arrow::ffi::FFI_ArrowArray>()
+            == std::mem::size_of::<polars_arrow::ffi::ArrowArray>()
+    );
+    assert!(
+        std::mem::align_of::<arrow::ffi::FFI_ArrowArray>()
+            == std::mem::align_of::<polars_arrow::ffi::ArrowArray>()
+    );
+    assert!(
+        std::mem::size_of::<arrow::ffi::FFI_ArrowSchema>()
+            == std::mem::size_of::<polars_arrow::ffi::ArrowSchema>()
+    );
+    assert!(
+        std::mem::align_of::<arrow::ffi::FFI_ArrowSchema>()
+            == std::mem::align_of::<polars_arrow::ffi::ArrowSchema>()
+    );
+};
+
+/// Convert a `RecordBatch` into a native polars `DataFrame` over the Arrow C
+/// data interface, without touching Python. Split out from
+/// [`record_batch_to_polars`] so the zero-copy hand-off can be unit-tested.
+fn record_batch_to_dataframe(batch: &RecordBatch) -> Result<DataFrame, PolarsError> {
+    let mut columns: Vec<Series> = Vec::with_capacity(batch.num_columns());
+    for (field, array) in batch.schema().fields().iter().zip(batch.columns()) {
+        // Export the arrow-rs array across the C data interface...
+        let (ffi_array, ffi_schema) = arrow::ffi::to_ffi(&array.to_data())
+            .map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+        // ...and import it into polars' arrow implementation. The layout
+        // assertions above pin the size/align these reinterpret casts rely on.
+        let series = unsafe {
+            let pl_field = polars_arrow::ffi::import_field_from_c(
+                &*(&ffi_schema as *const _ as *const polars_arrow::ffi::ArrowSchema),
+            )?;
+            let pl_array = polars_arrow::ffi::import_array_from_c(
+                std::ptr::read(&ffi_array as *const _ as *const polars_arrow::ffi::ArrowArray),
+                pl_field.dtype().clone(),
+            )?;
+            std::mem::forget(ffi_array);
+            Series::from_arrow(field.name().as_str().into(), pl_array)?
+        };
+        columns.push(series);
+    }
+    DataFrame::new(columns)
+}
+
+/// Write a single `RecordBatch` to disk as an Arrow IPC / Feather file.
+fn write_batch_ipc(batch: &RecordBatch, filepath: &str) -> Result<(), ArrowError> {
+    let file = File::create(filepath)
+        .map_err(|e| ArrowError::IoError(format!("Unable to create {filepath}"), e))?;
+    let mut writer = FileWriter::try_new(file, &batch.schema())?;
+    writer.write(batch)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Read an Arrow IPC / Feather file, concatenating its record batches into one.
+fn read_batch_ipc(filepath: &str) -> Result<RecordBatch, ArrowError> {
+    let file = File::open(filepath)
+        .map_err(|e| ArrowError::IoError(format!("Unable to open {filepath}"), e))?;
+    let reader = FileReader::try_new(file, None)?;
+    let schema = reader.schema();
+    let batches: Vec<RecordBatch> = reader.collect::<Result<_, _>>()?;
+    concat_batches(&schema, &batches)
+}
+
+/// Map an explicit `deck_type` string onto a [`Deck`].
+fn deck_from_str(s: &str) -> PyResult<Deck> {
+    match s.to_ascii_lowercase().as_str() {
+        "a" | "adeck" => Ok(Deck::A),
+        "b" | "bdeck" | "best" => Ok(Deck::B),
+        "f" | "fdeck" | "fix" => Ok(Deck::F),
+        other => Err(AdeckParseError::new_err(format!(
+            "unknown deck_type {other:?} (expected 'a', 'b', or 'f')"
+        ))),
+    }
 }
 
+/// Parse one or more ATCF deck files into a single table.
+///
+/// The deck variant (A/B/F) is taken from `deck_type` when given, otherwise
+/// sniffed from the first filename. Multiple files for the same storm are
+/// concatenated and rows repeating an already-seen row key dropped (the first
+/// one read is kept). Returns a `(data, errors)`
+/// tuple; set `strict` to raise on the first malformed row.
+#[pyfunction]
+#[pyo3(signature = (paths, deck_type = None, as_polars = false, strict = false))]
+fn parse_deck(
+    paths: Vec<String>,
+    deck_type: Option<&str>,
+    as_polars: bool,
+    strict: bool,
+    py: Python,
+) -> PyResult<PyObject> {
+    let explicit = deck_type.map(deck_from_str).transpose()?;
+    let deck = Deck::resolve(paths.first().map(String::as_str).unwrap_or(""), explicit);
+    let outcome = read_decks(&paths, deck, strict)?;
+    let data = if as_polars {
+        record_batch_to_polars(&outcome.batch, py)?
+    } else {
+        outcome.batch.to_pyarrow(py)?
+    };
+    let errors: Vec<String> = outcome.errors.iter().map(AdeckError::to_string).collect();
+    Ok((data, errors).into_py(py))
+}
 
+/// Parse an A-deck file and write the result straight to an Arrow IPC /
+/// Feather file, without round-tripping through Python.
+#[pyfunction]
+fn parse_adeck_to_ipc(src: &str, dst: &str) -> PyResult<()> {
+    let outcome = read_adeck_file_parallel(src, None, true)?;
+    write_batch_ipc(&outcome.batch, dst)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float32Array, Int32Array};
+
+    #[test]
+    fn polars_roundtrip_preserves_shape_and_values() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("wind_speed", DataType::Int32, true),
+            Field::new("latitude", DataType::Float32, true),
+            Field::new("model", DataType::Utf8, true),
+        ]));
+        let wind: ArrayRef = Arc::new(Int32Array::from(vec![Some(35), None, Some(64)]));
+        let lat: ArrayRef = Arc::new(Float32Array::from(vec![Some(26.1_f32), Some(-12.0), None]));
+        // A variable-length column pulls in the offsets buffer alongside the
+        // validity/data buffers the fixed-width columns above exercise.
+        let model: ArrayRef = Arc::new(StringArray::from(vec![Some("AVNO"), Some("GFSO"), None]));
+        let batch = RecordBatch::try_new(schema, vec![wind, lat, model]).unwrap();
+
+        let df = record_batch_to_dataframe(&batch).unwrap();
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(
+            df.get_column_names(),
+            vec!["wind_speed", "latitude", "model"]
+        );
+        // Nulls survive the zero-copy hand-off.
+        assert_eq!(df.column("wind_speed").unwrap().null_count(), 1);
+        assert_eq!(df.column("latitude").unwrap().null_count(), 1);
+        assert_eq!(df.column("model").unwrap().null_count(), 1);
+        let model_col = df.column("model").unwrap().str().unwrap();
+        assert_eq!(model_col.get(0), Some("AVNO"));
+        assert_eq!(model_col.get(1), Some("GFSO"));
+        assert_eq!(model_col.get(2), None);
+    }
+}
 
 #[pymodule]
-fn adeck_parser(_py: Python, m: &PyModule) -> PyResult<()> {
-    // m.add_function(wrap_pyfunction!(parse_adeck, m)?)?;
+fn adeck_parser(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse_adeck, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_deck, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_adeck_to_ipc, m)?)?;
     m.add_function(wrap_pyfunction!(create_pyarrow_table, m)?)?;
     m.add_class::<ArrowDataFrame>()?;
+    m.add("AdeckParseError", py.get_type::<AdeckParseError>())?;
     Ok(())
 }
 